@@ -17,6 +17,162 @@ impl<'input> CapturesExtension<'input> for regex::Captures<'input> {
     }
 }
 
+// `scan`/`check` only ever match at the current position, so drop a leftmost
+// match that the regex engine found further along the remaining input.
+fn anchored<'input>(captures: Option<regex::Captures<'input>>) -> Option<regex::Captures<'input>> {
+    captures.and_then(|cap| {
+        if cap.full_match().start() == 0 {
+            Some(cap)
+        } else {
+            None
+        }
+    })
+}
+
+// The byte-scanner counterpart of `anchored`, keeping the two scanners in
+// lockstep on how `scan`/`check` match at the current position.
+fn anchored_bytes<'input>(
+    captures: Option<regex::bytes::Captures<'input>>,
+) -> Option<regex::bytes::Captures<'input>> {
+    captures.and_then(|cap| {
+        if cap.get(0).unwrap().start() == 0 {
+            Some(cap)
+        } else {
+            None
+        }
+    })
+}
+
+/// A strategy for advancing the scanner; the regex engine (`Regex`) is one
+/// backend, literal matchers avoid it altogether. An implementation inspects `haystack` from its first byte and
+/// returns the byte length consumed by the match, or `None` when it does not
+/// match. Anchored matchers (`Prefix`, `Glob`, `Regex`) only ever match at the
+/// first byte; forward-scanning matchers (`Substring`) may skip ahead and
+/// report the span up to and including the first occurrence.
+pub trait Matcher {
+    fn match_at(&self, haystack: &str) -> Option<usize>;
+}
+
+/// Matches when the haystack starts with the given literal.
+pub struct Prefix(pub String);
+
+/// Matches the first plain-text occurrence of the literal, consuming up to and
+/// including it.
+pub struct Substring(pub String);
+
+/// Matches the shortest prefix satisfying a shell-style glob (`*`, `?`).
+pub struct Glob(pub String);
+
+/// Matches a regular expression anchored at the start of the haystack.
+pub struct Regex(pub regex::Regex);
+
+impl Matcher for Prefix {
+    fn match_at(&self, haystack: &str) -> Option<usize> {
+        if haystack.starts_with(&self.0[..]) {
+            Some(self.0.len())
+        } else {
+            None
+        }
+    }
+}
+
+impl Matcher for Substring {
+    fn match_at(&self, haystack: &str) -> Option<usize> {
+        haystack.find(&self.0[..]).map(|start| start + self.0.len())
+    }
+}
+
+impl Matcher for Glob {
+    fn match_at(&self, haystack: &str) -> Option<usize> {
+        let pattern: Vec<char> = self.0.chars().collect();
+
+        if glob_matches(&pattern, &[]) {
+            return Some(0);
+        }
+
+        for (index, ch) in haystack.char_indices() {
+            let end = index + ch.len_utf8();
+            let prefix: Vec<char> = haystack[..end].chars().collect();
+            if glob_matches(&pattern, &prefix) {
+                return Some(end);
+            }
+        }
+
+        None
+    }
+}
+
+impl Matcher for Regex {
+    fn match_at(&self, haystack: &str) -> Option<usize> {
+        self.0.find(haystack).and_then(|info| {
+            if info.start() == 0 {
+                Some(info.end())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+// Recursive shell-style glob, matching `pattern` against the whole of `text`.
+// `*` matches any (possibly empty) run of characters, `?` matches exactly one.
+fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(&'*') => {
+            glob_matches(&pattern[1..], text)
+                || (!text.is_empty() && glob_matches(pattern, &text[1..]))
+        }
+        Some(&'?') => !text.is_empty() && glob_matches(&pattern[1..], &text[1..]),
+        Some(&c) => match text.first() {
+            Some(&t) if t == c => glob_matches(&pattern[1..], &text[1..]),
+            _ => false,
+        },
+    }
+}
+
+/// A regular expression compiled once and reused across scans, sparing hot
+/// loops the cost of recompiling the same pattern on every call.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    regex: regex::Regex,
+}
+
+/// Cursor bookkeeping shared by the `str` and byte scanners: moving the scan
+/// pointer always invalidates the captures of the previous scan. Keeping it in
+/// one place lets the two scanners stay in lockstep.
+///
+/// Only the position/matched bookkeeping is shared here. The scan bodies
+/// themselves can't be made generic over both scanners: `StringScanner` drives
+/// `regex::Regex` over `&str` and yields `regex::Captures`/`&str`, while
+/// `ByteScanner` drives the distinct `regex::bytes::Regex` over `&[u8]` and
+/// yields `regex::bytes::Captures`/`&[u8]`. The two regex engines and their
+/// capture/match types share no common trait to abstract over, so the scan
+/// logic is mirrored deliberately rather than unified.
+trait ScanCursor {
+    type Captures;
+
+    fn position_mut(&mut self) -> &mut usize;
+    fn matched_mut(&mut self) -> &mut Option<Self::Captures>;
+
+    fn jump_to(&mut self, position: usize) {
+        *self.position_mut() = position;
+        *self.matched_mut() = None;
+    }
+}
+
+impl<'input> ScanCursor for StringScanner<'input> {
+    type Captures = regex::Captures<'input>;
+
+    fn position_mut(&mut self) -> &mut usize {
+        &mut self.position
+    }
+
+    fn matched_mut(&mut self) -> &mut Option<Self::Captures> {
+        &mut self.matched
+    }
+}
+
 impl<'input> StringScanner<'input> {
     pub fn new(s: &'input str) -> StringScanner<'input> {
         StringScanner {
@@ -36,46 +192,129 @@ impl<'input> StringScanner<'input> {
 
     pub fn check(&mut self, pattern: &str) -> Option<&'input str> {
         let regex = regex::Regex::new(pattern).unwrap();
-        let matched = regex.captures(&self.string[self.position..]);
-
-        self.matched = matched;
-        self.matched.as_ref().map(|cap| cap.full_match().as_str())
+        self.check_regex(&regex)
     }
 
     pub fn check_until(&mut self, pattern: &str) -> Option<&'input str> {
         let regex = regex::Regex::new(pattern).unwrap();
-        let matched = regex.captures(&self.string[self.position..]);
-
-        self.matched = matched;
-        self.matched.as_ref().map(|cap| {
-            let info = cap.full_match();
-            &self.string[self.position..info.end()]
-        })
+        self.check_until_regex(&regex)
     }
 
     pub fn set_position(&mut self, position: usize) {
-        self.position = position;
-        self.matched = None;
+        self.jump_to(position);
     }
 
     pub fn scan(&mut self, pattern: &str) -> Option<&'input str> {
         let regex = regex::Regex::new(pattern).unwrap();
-        let matched = regex.captures(&self.string[self.position..]);
+        self.scan_regex(&regex)
+    }
+
+    pub fn scan_until(&mut self, pattern: &str) -> Option<&'input str> {
+        let regex = regex::Regex::new(pattern).unwrap();
+        self.scan_until_regex(&regex)
+    }
+
+    /// Compile a pattern once so it can be reused across scans without paying
+    /// the compilation cost on every call, returning the error for bad input
+    /// rather than panicking.
+    pub fn compile(pattern: &str) -> Result<Pattern, regex::Error> {
+        regex::Regex::new(pattern).map(|regex| Pattern { regex })
+    }
+
+    pub fn scan_pattern(&mut self, pattern: &Pattern) -> Option<&'input str> {
+        self.scan_regex(&pattern.regex)
+    }
+
+    pub fn check_pattern(&mut self, pattern: &Pattern) -> Option<&'input str> {
+        self.check_regex(&pattern.regex)
+    }
+
+    pub fn scan_until_pattern(&mut self, pattern: &Pattern) -> Option<&'input str> {
+        self.scan_until_regex(&pattern.regex)
+    }
+
+    pub fn check_until_pattern(&mut self, pattern: &Pattern) -> Option<&'input str> {
+        self.check_until_regex(&pattern.regex)
+    }
+
+    /// Match `pattern` at the current position, consume the match, and return
+    /// `template` with `$0`, `$1`, … and `${name}` expanded to the matched
+    /// capture groups.
+    pub fn scan_replace(&mut self, pattern: &Pattern, template: &str) -> Option<String> {
+        self.matched = anchored(pattern.regex.captures(&self.string[self.position..]));
 
-        self.matched = matched;
         if let Some(ref cap) = self.matched {
             let info = cap.full_match();
+            let mut out = String::new();
+            cap.expand(template, &mut out);
             self.position += info.end();
+            Some(out)
+        } else {
+            None
         }
+    }
 
-        self.matched.as_ref().map(|m| m.get(0).unwrap().as_str())
+    /// Walk from the current position to the end of the input, copying
+    /// unmatched spans verbatim into `out` and writing an expanded `template`
+    /// for every match of `pattern`.
+    pub fn gsub_into(&mut self, pattern: &Pattern, template: &str, out: &mut String) {
+        let rest = &self.string[self.position..];
+        let mut last = 0;
+
+        for cap in pattern.regex.captures_iter(rest) {
+            let info = cap.get(0).unwrap();
+            out.push_str(&rest[last..info.start()]);
+            cap.expand(template, out);
+            last = info.end();
+        }
+
+        out.push_str(&rest[last..]);
+        self.position = self.string.len();
+        self.matched = None;
     }
 
-    pub fn scan_until(&mut self, pattern: &str) -> Option<&'input str> {
-        let regex = regex::Regex::new(pattern).unwrap();
-        let matched = regex.captures(&self.string[self.position..]);
+    /// Like `scan`, but reports a bad pattern instead of panicking on it.
+    pub fn try_scan(&mut self, pattern: &str) -> Result<Option<&'input str>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(self.scan_regex(&regex))
+    }
+
+    /// Like `check`, but reports a bad pattern instead of panicking on it.
+    pub fn try_check(&mut self, pattern: &str) -> Result<Option<&'input str>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(self.check_regex(&regex))
+    }
+
+    /// Consume a literal string at the current position with a direct
+    /// `str::starts_with`, bypassing the regex engine entirely.
+    pub fn scan_str(&mut self, s: &str) -> Option<&'input str> {
+        self.matched = None;
+        if self.string[self.position..].starts_with(s) {
+            let start = self.position;
+            self.position += s.len();
+            Some(&self.string[start..self.position])
+        } else {
+            None
+        }
+    }
+
+    /// Consume a literal like `scan_str`, returning the number of bytes skipped.
+    pub fn skip_str(&mut self, s: &str) -> Option<usize> {
+        self.scan_str(s).map(|matched| matched.len())
+    }
+
+    fn scan_regex(&mut self, regex: &regex::Regex) -> Option<&'input str> {
+        self.matched = anchored(regex.captures(&self.string[self.position..]));
+        if let Some(ref cap) = self.matched {
+            let info = cap.full_match();
+            self.position += info.end();
+        }
 
-        self.matched = matched;
+        self.matched.as_ref().map(|m| m.get(0).unwrap().as_str())
+    }
+
+    fn scan_until_regex(&mut self, regex: &regex::Regex) -> Option<&'input str> {
+        self.matched = regex.captures(&self.string[self.position..]);
 
         if let Some(ref cap) = self.matched {
             let info = cap.full_match();
@@ -88,7 +327,20 @@ impl<'input> StringScanner<'input> {
             None
         }
     }
-    
+
+    fn check_regex(&mut self, regex: &regex::Regex) -> Option<&'input str> {
+        self.matched = anchored(regex.captures(&self.string[self.position..]));
+        self.matched.as_ref().map(|cap| cap.full_match().as_str())
+    }
+
+    fn check_until_regex(&mut self, regex: &regex::Regex) -> Option<&'input str> {
+        self.matched = regex.captures(&self.string[self.position..]);
+        self.matched.as_ref().map(|cap| {
+            let info = cap.full_match();
+            &self.string[self.position..self.position + info.end()]
+        })
+    }
+
     pub fn getch(&mut self) -> Option<&'input str> {
         self.scan(".")
     }
@@ -105,6 +357,20 @@ impl<'input> StringScanner<'input> {
         self.matched.as_ref().map(|m| m.full_match().as_str())
     }
 
+    pub fn group(&self, i: usize) -> Option<&'input str> {
+        self.matched.as_ref().and_then(|cap| cap.get(i)).map(|m| m.as_str())
+    }
+
+    pub fn named(&self, name: &str) -> Option<&'input str> {
+        self.matched.as_ref().and_then(|cap| cap.name(name)).map(|m| m.as_str())
+    }
+
+    pub fn captures(&self) -> Option<Vec<Option<&'input str>>> {
+        self.matched.as_ref().map(|cap| {
+            cap.iter().map(|group| group.map(|m| m.as_str())).collect()
+        })
+    }
+
     pub fn pre_match(&self) -> Option<&'input str> {
         self.matched.as_ref().map(|cap| {
             let matched = cap.full_match();
@@ -126,6 +392,211 @@ impl<'input> StringScanner<'input> {
             matched: None,
         }
     }
+
+    /// Consume a prefix of the remaining input that can be turned into
+    /// `needle` within `max_edits` insertions, deletions or substitutions,
+    /// returning that prefix. A Wagner–Fischer row is carried column by column
+    /// across the input; the prefix with the smallest edit distance wins, ties
+    /// going to the shorter prefix.
+    pub fn scan_fuzzy(&mut self, needle: &str, max_edits: usize) -> Option<&'input str> {
+        self.matched = None;
+
+        let needle_chars: Vec<char> = needle.chars().collect();
+        let m = needle_chars.len();
+
+        let mut dp: Vec<usize> = (0..m + 1).collect();
+        let mut best: Option<(usize, usize)> = None;
+
+        // An empty prefix already matches when `needle` can be reached by pure
+        // insertion within the budget (and always when `needle` is empty).
+        if dp[m] <= max_edits {
+            best = Some((0, dp[m]));
+        }
+
+        let rest = &self.string[self.position..];
+        let mut column = 0;
+        for (_, input_char) in rest.char_indices() {
+            column += 1;
+
+            let mut new = vec![0usize; m + 1];
+            new[0] = column;
+            for i in 1..m + 1 {
+                let cost = if needle_chars[i - 1] == input_char { 0 } else { 1 };
+                new[i] = ::std::cmp::min(
+                    ::std::cmp::min(dp[i] + 1, new[i - 1] + 1),
+                    dp[i - 1] + cost,
+                );
+            }
+
+            if new[m] <= max_edits {
+                let better = best.is_none_or(|(_, distance)| new[m] < distance);
+                if better {
+                    best = Some((column, new[m]));
+                }
+            }
+
+            let row_min = *new.iter().min().unwrap();
+            dp = new;
+
+            // No reachable longer prefix can come back under budget once the
+            // whole row has exceeded it.
+            if row_min > max_edits {
+                break;
+            }
+        }
+
+        match best {
+            Some((chars, _)) => {
+                let byte_len = rest
+                    .char_indices()
+                    .nth(chars)
+                    .map(|(offset, _)| offset)
+                    .unwrap_or(rest.len());
+                let start = self.position;
+                self.position += byte_len;
+                Some(&self.string[start..start + byte_len])
+            }
+            None => None,
+        }
+    }
+
+    pub fn scan_with(&mut self, m: &dyn Matcher) -> Option<&'input str> {
+        self.matched = None;
+        match m.match_at(&self.string[self.position..]) {
+            Some(len) => {
+                let start = self.position;
+                self.position += len;
+                Some(&self.string[start..start + len])
+            }
+            None => None,
+        }
+    }
+
+    pub fn check_with(&mut self, m: &dyn Matcher) -> Option<&'input str> {
+        self.matched = None;
+        m.match_at(&self.string[self.position..])
+            .map(|len| &self.string[self.position..self.position + len])
+    }
+
+    pub fn scan_until_with(&mut self, m: &dyn Matcher) -> Option<&'input str> {
+        self.matched = None;
+        let rest = &self.string[self.position..];
+
+        let offsets = rest.char_indices().map(|(offset, _)| offset);
+        for offset in offsets {
+            if let Some(len) = m.match_at(&rest[offset..]) {
+                let start = self.position;
+                let end = self.position + offset + len;
+                self.position = end;
+                return Some(&self.string[start..end]);
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct ByteScanner<'input> {
+    bytes: &'input [u8],
+    position: usize,
+    matched: Option<regex::bytes::Captures<'input>>
+}
+
+impl<'input> ScanCursor for ByteScanner<'input> {
+    type Captures = regex::bytes::Captures<'input>;
+
+    fn position_mut(&mut self) -> &mut usize {
+        &mut self.position
+    }
+
+    fn matched_mut(&mut self) -> &mut Option<Self::Captures> {
+        &mut self.matched
+    }
+}
+
+impl<'input> ByteScanner<'input> {
+    pub fn new(bytes: &'input [u8]) -> ByteScanner<'input> {
+        ByteScanner {
+            bytes,
+            position: 0,
+            matched: None
+        }
+    }
+
+    pub fn beginning_of_line(&self) -> bool {
+        self.position == 0 || self.bytes[self.position - 1] == b'\n'
+    }
+
+    pub fn bol(&self) -> bool {
+        self.beginning_of_line()
+    }
+
+    pub fn set_position(&mut self, position: usize) {
+        self.jump_to(position);
+    }
+
+    pub fn check(&mut self, pattern: &str) -> Option<&'input [u8]> {
+        let regex = regex::bytes::Regex::new(pattern).unwrap();
+        self.matched = anchored_bytes(regex.captures(&self.bytes[self.position..]));
+        self.matched.as_ref().map(|cap| cap.get(0).unwrap().as_bytes())
+    }
+
+    pub fn scan(&mut self, pattern: &str) -> Option<&'input [u8]> {
+        let regex = regex::bytes::Regex::new(pattern).unwrap();
+        self.matched = anchored_bytes(regex.captures(&self.bytes[self.position..]));
+        if let Some(ref cap) = self.matched {
+            self.position += cap.get(0).unwrap().end();
+        }
+
+        self.matched.as_ref().map(|cap| cap.get(0).unwrap().as_bytes())
+    }
+
+    pub fn scan_until(&mut self, pattern: &str) -> Option<&'input [u8]> {
+        let regex = regex::bytes::Regex::new(pattern).unwrap();
+        self.matched = regex.captures(&self.bytes[self.position..]);
+
+        if let Some(ref cap) = self.matched {
+            let end = cap.get(0).unwrap().end();
+            let result = Some(&self.bytes[self.position..self.position+end]);
+
+            self.position += end;
+
+            result
+        } else {
+            None
+        }
+    }
+
+    pub fn getch(&mut self) -> Option<&'input [u8]> {
+        self.scan(".")
+    }
+
+    pub fn matched(&self) -> Option<&'input [u8]> {
+        self.matched.as_ref().map(|cap| cap.get(0).unwrap().as_bytes())
+    }
+
+    pub fn pre_match(&self) -> Option<&'input [u8]> {
+        self.matched.as_ref().map(|cap| {
+            let matched = cap.get(0).unwrap();
+
+            &self.bytes[..self.position-matched.as_bytes().len()]
+        })
+    }
+
+    pub fn post_match(&self) -> Option<&'input [u8]> {
+        self.matched.as_ref().map(|_| {
+            &self.bytes[self.position..]
+        })
+    }
+
+    pub fn subscan(&self) -> ByteScanner<'input> {
+        ByteScanner {
+            bytes: &self.bytes[self.position..],
+            position: 0,
+            matched: None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -401,3 +872,212 @@ mod post_match {
         }
     }
 }
+
+#[cfg(test)]
+mod byte_scanner {
+    mod should {
+        use ByteScanner;
+
+        #[test]
+        fn scan_arbitrary_bytes_returning_slices() {
+            let mut s = ByteScanner::new(b"This is a test");
+            assert_eq!(s.scan(r#"\w+"#), Some(&b"This"[..]));
+            assert_eq!(s.scan(r#"\s+"#), Some(&b" "[..]));
+            assert_eq!(s.scan(r#"\d"#), None);
+            assert_eq!(s.matched(), None);
+        }
+
+        #[test]
+        fn report_pre_and_post_match_as_bytes() {
+            let mut s = ByteScanner::new(b"This is a test");
+            assert_eq!(s.scan_until(r#"a"#), Some(&b"This is a"[..]));
+            assert_eq!(s.pre_match(), Some(&b"This is "[..]));
+            assert_eq!(s.post_match(), Some(&b" test"[..]));
+        }
+
+        #[test]
+        fn track_the_beginning_of_the_line() {
+            let mut s = ByteScanner::new(b"hello\nworld");
+            assert!(s.beginning_of_line());
+            s.set_position(1);
+            assert!(!s.beginning_of_line());
+            s.set_position(6);
+            assert!(s.bol());
+        }
+    }
+}
+
+#[cfg(test)]
+mod compile {
+    mod should {
+        use StringScanner;
+
+        #[test]
+        fn reuse_a_compiled_pattern_across_scans() {
+            let word = StringScanner::compile(r#"\w+"#).unwrap();
+            let mut s = StringScanner::new("This is a test");
+            assert_eq!(s.scan_pattern(&word), Some("This"));
+            assert_eq!(s.check_pattern(&word), None);
+            s.scan(r#"\s+"#);
+            assert_eq!(s.scan_pattern(&word), Some("is"));
+        }
+
+        #[test]
+        fn report_a_bad_pattern_instead_of_panicking() {
+            assert!(StringScanner::compile(r#"("#).is_err());
+            let mut s = StringScanner::new("This is a test");
+            assert!(s.try_scan(r#"("#).is_err());
+            assert_eq!(s.try_scan(r#"\w+"#).unwrap(), Some("This"));
+            assert_eq!(s.try_check(r#"\s"#).unwrap(), Some(" "));
+        }
+    }
+}
+
+#[cfg(test)]
+mod captures {
+    mod should {
+        use StringScanner;
+
+        #[test]
+        fn expose_positional_and_named_groups_of_the_last_scan() {
+            let mut s = StringScanner::new("key=value");
+            s.scan(r#"(?P<key>\w+)=(?P<val>\w+)"#);
+            assert_eq!(s.group(0), Some("key=value"));
+            assert_eq!(s.group(1), Some("key"));
+            assert_eq!(s.group(2), Some("value"));
+            assert_eq!(s.named("key"), Some("key"));
+            assert_eq!(s.named("val"), Some("value"));
+            assert_eq!(s.group(3), None);
+        }
+
+        #[test]
+        fn collect_all_groups_and_return_nil_without_a_match() {
+            let mut s = StringScanner::new("key=value");
+            s.scan(r#"(\w+)=(\w+)"#);
+            assert_eq!(
+                s.captures(),
+                Some(vec![Some("key=value"), Some("key"), Some("value")])
+            );
+
+            let mut s = StringScanner::new("key=value");
+            s.scan(r#"\d+"#);
+            assert_eq!(s.captures(), None);
+            assert_eq!(s.group(0), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod scan_replace {
+    mod should {
+        use StringScanner;
+
+        #[test]
+        fn expand_capture_templates_for_the_match_at_the_position() {
+            let pat = StringScanner::compile(r#"(?P<key>\w+)=(?P<val>\w+)"#).unwrap();
+            let mut s = StringScanner::new("a=1;b=2");
+            assert_eq!(s.scan_replace(&pat, "${val}:${key}"), Some("1:a".to_string()));
+            assert_eq!(s.scan_str(";"), Some(";"));
+            assert_eq!(s.scan_replace(&pat, "$1=$2"), Some("b=2".to_string()));
+        }
+
+        #[test]
+        fn rewrite_every_match_through_to_the_end() {
+            let pat = StringScanner::compile(r#"(\w+)"#).unwrap();
+            let mut s = StringScanner::new("a=1;b=2");
+            let mut out = String::new();
+            s.gsub_into(&pat, "[$1]", &mut out);
+            assert_eq!(out, "[a]=[1];[b]=[2]");
+        }
+    }
+}
+
+#[cfg(test)]
+mod scan_str {
+    mod should {
+        use StringScanner;
+
+        #[test]
+        fn consume_a_literal_without_the_regex_engine() {
+            let mut s = StringScanner::new("key = value");
+            assert_eq!(s.scan_str("key"), Some("key"));
+            assert_eq!(s.scan_str("key"), None);
+            assert_eq!(s.skip_str(" = "), Some(3));
+            assert_eq!(s.scan_str("value"), Some("value"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod scan_fuzzy {
+    mod should {
+        use StringScanner;
+
+        #[test]
+        fn consume_an_exact_prefix_with_zero_distance() {
+            let mut s = StringScanner::new("hello world");
+            assert_eq!(s.scan_fuzzy("hello", 0), Some("hello"));
+            assert_eq!(s.position, 5);
+        }
+
+        #[test]
+        fn tolerate_edits_up_to_the_budget() {
+            let mut s = StringScanner::new("helo world");
+            assert_eq!(s.scan_fuzzy("hello", 1), Some("helo"));
+        }
+
+        #[test]
+        fn return_none_when_no_prefix_is_close_enough() {
+            let mut s = StringScanner::new("xyz");
+            assert_eq!(s.scan_fuzzy("hello", 1), None);
+            assert_eq!(s.position, 0);
+        }
+
+        #[test]
+        fn match_the_empty_prefix_for_an_empty_needle() {
+            let mut s = StringScanner::new("hello");
+            assert_eq!(s.scan_fuzzy("", 0), Some(""));
+            assert_eq!(s.position, 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod scan_with {
+    mod should {
+        use {Glob, Prefix, StringScanner, Substring};
+
+        #[test]
+        fn advance_over_a_prefix_without_touching_the_regex_engine() {
+            let mut s = StringScanner::new("key = value");
+            assert_eq!(s.scan_with(&Prefix("key".to_string())), Some("key"));
+            assert_eq!(s.scan_with(&Prefix("key".to_string())), None);
+            assert_eq!(s.scan_with(&Prefix(" = ".to_string())), Some(" = "));
+        }
+
+        #[test]
+        fn check_does_not_advance() {
+            let mut s = StringScanner::new("key = value");
+            assert_eq!(s.check_with(&Prefix("key".to_string())), Some("key"));
+            assert_eq!(s.check_with(&Prefix("key".to_string())), Some("key"));
+        }
+
+        #[test]
+        fn substring_consumes_up_to_and_including_the_match() {
+            let mut s = StringScanner::new("This is a test");
+            assert_eq!(s.scan_with(&Substring("is".to_string())), Some("This"));
+        }
+
+        #[test]
+        fn glob_matches_the_shortest_prefix() {
+            let mut s = StringScanner::new("foo.rs rest");
+            assert_eq!(s.scan_with(&Glob("*.rs".to_string())), Some("foo.rs"));
+        }
+
+        #[test]
+        fn scan_until_with_stops_at_the_first_match() {
+            let mut s = StringScanner::new("This is a test");
+            assert_eq!(s.scan_until_with(&Prefix("a".to_string())), Some("This is a"));
+        }
+    }
+}